@@ -0,0 +1,96 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The exponential distribution, sampled via the ziggurat algorithm.
+
+use Rng;
+use super::ziggurat;
+use super::ziggurat_tables as tables;
+
+/// Sample a standard exponential variate, `Exp(1)`.
+#[inline]
+pub fn sample_standard<R: Rng>(rng: &mut R) -> f64 {
+    #[inline]
+    fn pdf(x: f64) -> f64 {
+        (-x).exp()
+    }
+    #[inline]
+    fn zero_case<R: Rng>(rng: &mut R, _u: f64) -> f64 {
+        tables::ZIG_EXP_R - rng.gen::<f64>().ln()
+    }
+
+    ziggurat(rng, false, &tables::ZIG_EXP_X, &tables::ZIG_EXP_F, pdf, zero_case)
+}
+
+/// The exponential distribution `Exp(lambda)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Exponential {
+    /// `1 / lambda`, so sampling is a single multiply.
+    lambda_inv: f64,
+}
+
+impl Exponential {
+    /// Construct a new `Exponential` with rate `lambda`.
+    ///
+    /// Panics if `lambda <= 0`.
+    #[inline]
+    pub fn new(lambda: f64) -> Exponential {
+        assert!(lambda > 0.0, "Exponential::new called with lambda <= 0");
+        Exponential { lambda_inv: 1.0 / lambda }
+    }
+
+    /// Draw a sample from this distribution.
+    #[inline]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        sample_standard(rng) * self.lambda_inv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Exponential, sample_standard};
+
+    #[test]
+    fn standard_exponential_mean() {
+        let mut rng = ::test::rng(221);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| sample_standard(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        assert!((mean - 1.0).abs() < 0.01, "mean was {}", mean);
+        assert!((var - 1.0).abs() < 0.03, "variance was {}", var);
+    }
+
+    #[test]
+    fn exponential_matches_mean() {
+        let mut rng = ::test::rng(222);
+        let dist = Exponential::new(0.5);
+        let n = 100_000;
+        let sum: f64 = (0..n).map(|_| dist.sample(&mut rng)).sum();
+        let mean = sum / n as f64;
+        // mean of Exp(lambda) is 1/lambda
+        assert!((mean - 2.0).abs() < 0.03, "mean was {}", mean);
+    }
+
+    // Regression test for a ziggurat table bug where the bottom layer's
+    // `zero_case` only ever produced `x >= R`, leaving the wedge just
+    // below `R` unreachable by any code path. Check the tail fraction
+    // directly, since it's small enough that a plain mean/variance
+    // check doesn't reliably catch a truncated tail.
+    #[test]
+    fn standard_exponential_tail_is_populated() {
+        let mut rng = ::test::rng(223);
+        let n = 1_000_000;
+        let beyond = (0..n).filter(|_| sample_standard(&mut rng) > 8.0).count();
+        let frac = beyond as f64 / n as f64;
+        // true value is exp(-8) =~ 3.35e-4.
+        assert!((frac - 3.35e-4).abs() < 2e-4, "tail fraction was {}", frac);
+    }
+}
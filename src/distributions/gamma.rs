@@ -0,0 +1,203 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Gamma distribution and the ChiSquared/Beta distributions built
+//! on top of it, sampled via the Marsaglia-Tsang method.
+
+use Rng;
+use super::normal;
+
+/// The Gamma distribution `Gamma(shape, scale)`.
+///
+/// Uses the Marsaglia-Tsang method, which for `shape >= 1` rejects a
+/// transformed standard normal draw against a cheap squared-normal
+/// bound before falling back to the exact (but `ln`-heavy) density
+/// check, so the common case costs one normal draw, one uniform draw
+/// and no logarithms at all. Correctness here rests entirely on
+/// `normal::sample_standard` covering the full range of `N(0, 1)`,
+/// tail included — this sampler does no bounds checking of its own.
+#[derive(Clone, Copy, Debug)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+    repr: GammaRepr,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum GammaRepr {
+    Large { d: f64, c: f64 },
+    Small { inv_shape: f64 },
+}
+
+impl Gamma {
+    /// Construct a new `Gamma` with the given shape and scale
+    /// parameters.
+    ///
+    /// Panics if `shape <= 0` or `scale <= 0`.
+    pub fn new(shape: f64, scale: f64) -> Gamma {
+        assert!(shape > 0.0, "Gamma::new called with shape <= 0");
+        assert!(scale > 0.0, "Gamma::new called with scale <= 0");
+
+        let repr = if shape >= 1.0 {
+            let d = shape - 1.0 / 3.0;
+            GammaRepr::Large { d, c: 1.0 / (9.0 * d).sqrt() }
+        } else {
+            GammaRepr::Small { inv_shape: 1.0 / shape }
+        };
+
+        Gamma { shape, scale, repr }
+    }
+
+    /// Draw a sample from this distribution.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        match self.repr {
+            GammaRepr::Large { d, c } => large_shape(rng, d, c) * self.scale,
+            GammaRepr::Small { inv_shape } => {
+                // Gamma(a) for 0 < a < 1 is Gamma(a+1) scaled by
+                // u^(1/a) for an independent uniform u, per Marsaglia
+                // & Tsang (2000), section 6.
+                let d = self.shape + 1.0 - 1.0 / 3.0;
+                let c = 1.0 / (9.0 * d).sqrt();
+                let g = large_shape(rng, d, c);
+                g * rng.gen::<f64>().powf(inv_shape) * self.scale
+            }
+        }
+    }
+}
+
+/// Marsaglia-Tsang sampling for `shape >= 1`, with `d = shape - 1/3`
+/// and `c = 1/sqrt(9d)` precomputed by the caller.
+#[inline]
+fn large_shape<R: Rng>(rng: &mut R, d: f64, c: f64) -> f64 {
+    loop {
+        let x = normal::sample_standard(rng);
+        let v_cbrt = 1.0 + c * x;
+        if v_cbrt <= 0.0 {
+            continue;
+        }
+        let v = v_cbrt * v_cbrt * v_cbrt;
+
+        let u: f64 = rng.gen();
+        let x2 = x * x;
+        if u < 1.0 - 0.0331 * x2 * x2 {
+            return d * v;
+        }
+        if u.ln() < 0.5 * x2 + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
+
+/// The chi-squared distribution `ChiSquared(k)`, i.e. `Gamma(k/2, 2)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared {
+    gamma: Gamma,
+}
+
+impl ChiSquared {
+    /// Construct a new `ChiSquared` with `k` degrees of freedom.
+    ///
+    /// Panics if `k <= 0`.
+    pub fn new(k: f64) -> ChiSquared {
+        ChiSquared { gamma: Gamma::new(0.5 * k, 2.0) }
+    }
+
+    /// Draw a sample from this distribution.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        self.gamma.sample(rng)
+    }
+}
+
+/// The Beta distribution `Beta(alpha, beta)`.
+///
+/// Sampled as `X / (X + Y)` with `X ~ Gamma(alpha, 1)` and
+/// `Y ~ Gamma(beta, 1)` drawn independently.
+#[derive(Clone, Copy, Debug)]
+pub struct Beta {
+    gamma_alpha: Gamma,
+    gamma_beta: Gamma,
+}
+
+impl Beta {
+    /// Construct a new `Beta` with the given shape parameters.
+    ///
+    /// Panics if `alpha <= 0` or `beta <= 0`.
+    pub fn new(alpha: f64, beta: f64) -> Beta {
+        Beta {
+            gamma_alpha: Gamma::new(alpha, 1.0),
+            gamma_beta: Gamma::new(beta, 1.0),
+        }
+    }
+
+    /// Draw a sample from this distribution.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        let x = self.gamma_alpha.sample(rng);
+        let y = self.gamma_beta.sample(rng);
+        x / (x + y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gamma, ChiSquared, Beta};
+
+    fn mean_var(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        (mean, var)
+    }
+
+    #[test]
+    fn gamma_moments_large_shape() {
+        let mut rng = ::test::rng(231);
+        let dist = Gamma::new(3.0, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| dist.sample(&mut rng)).collect();
+        let (mean, var) = mean_var(&samples);
+        // Gamma(k, theta) has mean k*theta, variance k*theta^2.
+        // `large_shape` draws its inner normal from
+        // `normal::sample_standard`, so errors there feed into these
+        // moments too. A truncated normal tail shifts them by less than
+        // the sampling noise of 100,000 draws, so these tolerances only
+        // catch gross errors; the tail itself is checked in `normal`.
+        assert!((mean - 6.0).abs() < 0.05, "mean was {}", mean);
+        assert!((var - 12.0).abs() < 0.3, "variance was {}", var);
+    }
+
+    #[test]
+    fn gamma_moments_small_shape() {
+        let mut rng = ::test::rng(232);
+        let dist = Gamma::new(0.5, 1.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| dist.sample(&mut rng)).collect();
+        let (mean, _) = mean_var(&samples);
+        assert!((mean - 0.5).abs() < 0.02, "mean was {}", mean);
+    }
+
+    #[test]
+    fn chi_squared_mean() {
+        let mut rng = ::test::rng(233);
+        let dist = ChiSquared::new(4.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| dist.sample(&mut rng)).collect();
+        let (mean, var) = mean_var(&samples);
+        // ChiSquared(k) has mean k, variance 2k.
+        assert!((mean - 4.0).abs() < 0.05, "mean was {}", mean);
+        assert!((var - 8.0).abs() < 0.3, "variance was {}", var);
+    }
+
+    #[test]
+    fn beta_mean() {
+        let mut rng = ::test::rng(234);
+        let dist = Beta::new(2.0, 3.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| dist.sample(&mut rng)).collect();
+        let (mean, _) = mean_var(&samples);
+        // mean of Beta(a,b) is a/(a+b)
+        assert!((mean - 0.4).abs() < 0.01, "mean was {}", mean);
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sampling from probability distributions that are not directly
+//! expressible as a uniform `Rand` impl.
+//!
+//! The uniform `f64`/`f32` and integer impls in `rand_impls` are the
+//! building blocks; everything here is layered on top of them.
+
+use Rng;
+
+mod ziggurat_tables;
+
+pub mod normal;
+pub mod exponential;
+pub mod gamma;
+
+pub use self::normal::Normal;
+pub use self::exponential::Exponential;
+pub use self::gamma::{Gamma, ChiSquared, Beta};
+
+/// Sample a value from a distribution whose density is built as a
+/// ziggurat: `N` horizontal layers of equal area `v`, each bounded
+/// above by `x_tab[i+1]` and below by `x_tab[i]`.
+///
+/// Most draws land in the rectangular core of the chosen layer and
+/// are accepted immediately; `pdf` and `zero_case` are only consulted
+/// on the slow path, which the construction keeps rare (about 1% of
+/// draws for the tables in `ziggurat_tables`).
+///
+/// * `symmetric`: whether this is a two-sided distribution (the
+///   normal) or one-sided (the exponential) — controls whether a
+///   random sign is folded into `u`.
+/// * `pdf`: the true density, used to accept/reject within a layer
+///   that isn't the rectangular core.
+/// * `zero_case`: called when the bottom layer (`i == 0`) is picked
+///   and the draw misses the core. Layer 0 is the combined tail box
+///   (see `ziggurat_tables`), whose core already spans the entire
+///   real region `[0, x_tab[1])`, so reaching this branch means the
+///   draw landed at or beyond `x_tab[1]` — the true unbounded tail —
+///   which `zero_case` resamples directly rather than testing against
+///   `pdf`.
+#[inline]
+fn ziggurat<R: Rng, P, Z>(rng: &mut R,
+                          symmetric: bool,
+                          x_tab: &'static [f64; 257],
+                          f_tab: &'static [f64; 257],
+                          mut pdf: P,
+                          mut zero_case: Z)
+                          -> f64
+    where P: FnMut(f64) -> f64, Z: FnMut(&mut R, f64) -> f64
+{
+    const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+
+    loop {
+        // a random u64 gives us both the layer index (low 8 bits,
+        // since there are 256 layers) and 53 bits of uniform `f` to
+        // place ourselves within it.
+        let bits: u64 = rng.next_u64();
+        let i = (bits & 0xff) as usize;
+        let f = (bits >> 11) as f64 * SCALE;
+
+        let u = if symmetric { 2.0 * f - 1.0 } else { f };
+        let x = u * x_tab[i];
+
+        let test_x = if symmetric { x.abs() } else { x };
+
+        // the common case: we're in the rectangular core of the
+        // layer, strictly below the previous layer's edge.
+        if test_x < x_tab[i + 1] {
+            return x;
+        }
+
+        if i == 0 {
+            return zero_case(rng, u);
+        }
+
+        // not in the core: accept with probability proportional to
+        // how far under the true density `x` falls, between this
+        // layer's density and the one above it.
+        if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * rng.gen::<f64>() < pdf(x) {
+            return x;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The normal distribution, sampled via the ziggurat algorithm.
+
+use Rng;
+use super::ziggurat;
+use super::ziggurat_tables as tables;
+
+/// Sample a standard normal variate, `N(0, 1)`.
+///
+/// This is the building block `Normal` scales and shifts, and is also
+/// reused by the `Gamma` sampler for its inner normal draws.
+#[inline]
+pub fn sample_standard<R: Rng>(rng: &mut R) -> f64 {
+    #[inline]
+    fn pdf(x: f64) -> f64 {
+        (-x * x / 2.0).exp()
+    }
+    // falling back to the tail when the bottom layer is picked: the
+    // density beyond `x_tab[1]` is handled by sampling the
+    // exponential tail of the half-normal and rejecting until the
+    // point falls under the true curve.
+    #[inline]
+    fn zero_case<R: Rng>(rng: &mut R, u: f64) -> f64 {
+        let mut x;
+        let mut y;
+        loop {
+            x = rng.gen::<f64>().ln() / tables::ZIG_NORM_R;
+            y = rng.gen::<f64>().ln();
+            if -2.0 * y >= x * x {
+                break;
+            }
+        }
+        if u < 0.0 {
+            x - tables::ZIG_NORM_R
+        } else {
+            tables::ZIG_NORM_R - x
+        }
+    }
+
+    ziggurat(rng, true, &tables::ZIG_NORM_X, &tables::ZIG_NORM_F, pdf, zero_case)
+}
+
+/// The normal distribution `N(mean, std_dev^2)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Construct a new `Normal` with the given mean and standard
+    /// deviation.
+    ///
+    /// Panics if `std_dev < 0`.
+    #[inline]
+    pub fn new(mean: f64, std_dev: f64) -> Normal {
+        assert!(std_dev >= 0.0, "Normal::new called with std_dev < 0");
+        Normal { mean, std_dev }
+    }
+
+    /// Draw a sample from this distribution.
+    #[inline]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        self.mean + self.std_dev * sample_standard(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Normal, sample_standard};
+
+    #[test]
+    fn standard_normal_moments() {
+        let mut rng = ::test::rng(212);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| sample_standard(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 0.01, "mean was {}", mean);
+        assert!((var - 1.0).abs() < 0.03, "variance was {}", var);
+    }
+
+    #[test]
+    fn normal_matches_mean_and_variance() {
+        let mut rng = ::test::rng(213);
+        let dist = Normal::new(10.0, 2.0);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| dist.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n as f64;
+        assert!((mean - 10.0).abs() < 0.05, "mean was {}", mean);
+        assert!((var - 4.0).abs() < 0.1, "variance was {}", var);
+    }
+
+    // Regression test for a ziggurat table bug where the bottom layer's
+    // `zero_case` only ever produced `|x| >= R`, leaving the wedge just
+    // below `R` unreachable by any code path. A moment check alone
+    // doesn't reliably catch this (the mass involved is a fraction of a
+    // percent), so check the tail directly: the fraction of draws with
+    // `|x| > 3.5` should track `2 * (1 - Phi(3.5))` closely, not come up
+    // short.
+    #[test]
+    fn standard_normal_tail_is_populated() {
+        let mut rng = ::test::rng(214);
+        let n = 1_000_000;
+        let beyond = (0..n).filter(|_| sample_standard(&mut rng).abs() > 3.5).count();
+        let frac = beyond as f64 / n as f64;
+        // true value is ~4.65e-4; a truncated tail would land far below
+        // this, so a generous two-sided band is enough to catch it.
+        assert!((frac - 4.65e-4).abs() < 2e-4, "tail fraction was {}", frac);
+    }
+}
@@ -0,0 +1,1084 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Precomputed ziggurat tables for the normal and exponential
+//! distributions, generated offline so that sampling never has to
+//! solve for the layer boundaries at runtime.
+//!
+//! Layer 0 is special: it pairs the unbounded tail (everything beyond
+//! `ZIG_*_R`) with the widest real layer into one combined region, so
+//! its right edge `x_tab[0]` is *not* a real layer boundary but an
+//! inflated width `q = v / f(r)` wide enough that any draw landing
+//! inside `[0, r)` can be accepted outright — the density there is
+//! never below `f(r)`. Draws that land in `[r, q)` instead fall
+//! through to `zero_case`, which resamples from the true unbounded
+//! tail. `x_tab[1] == ZIG_*_R` exactly, so this leaves no gap between
+//! the fast path and the tail: every value in `[0, r)` is handled by
+//! the ordinary core/wedge checks, and everything at or beyond `r` by
+//! `zero_case`.
+//!
+//! Layers 1..255 are real layers of equal area `v`, each _X table
+//! holding the right edge `x_tab[i]` in *descending* order down to
+//! `x_tab[255]` (the apex-adjacent layer), plus a trailing sentinel
+//! `x_tab[256] == 0.0`. The matching _F table holds the density
+//! `f(x_tab[i])` at that edge, in ascending order from `f_tab[1]`,
+//! with `f_tab[256] == 1.0` (the density at the apex) as its
+//! sentinel; `f_tab[0]` is unused padding (layer 0 never runs the
+//! generic wedge check) and is set to `f(r)` for documentation only.
+//! Both tables have 257 entries for the 256 layers.
+
+/// Where the exact unbounded tail begins: `x_tab[1]`, i.e. the edge
+/// of the widest *real* (non tail-box) layer.
+pub const ZIG_NORM_R: f64 = 3.6541528853610092;
+
+pub const ZIG_NORM_X: [f64; 257] = [
+    3.9107579595249167,
+    3.6541528853610092,
+    3.4492782985614316,
+    3.320244733839826,
+    3.2245750520478023,
+    3.1478892895180013,
+    3.083526132002144,
+    3.027837791769594,
+    2.9786032798818436,
+    2.934366867208888,
+    2.8941210536134125,
+    2.857138730873225,
+    2.8228773968264433,
+    2.790921174001928,
+    2.760944005279987,
+    2.7326853590440123,
+    2.7059336561230634,
+    2.680514643285746,
+    2.656283037576744,
+    2.6331163936315836,
+    2.6109105184888244,
+    2.5895759867082875,
+    2.5690354526818444,
+    2.5492215503247837,
+    2.5300752321598545,
+    2.5115444416266945,
+    2.4935830412710467,
+    2.476149939670523,
+    2.459208374334705,
+    2.442725318200364,
+    2.4266709849371466,
+    2.4110184139011195,
+    2.3957431197819274,
+    2.3808227951720857,
+    2.366237056717291,
+    2.3519672273791445,
+    2.3379961487965284,
+    2.3243080188711325,
+    2.310888250601372,
+    2.2977233489028634,
+    2.284800802724492,
+    2.2721089902283818,
+    2.2596370951737876,
+    2.247375032947389,
+    2.235313384929921,
+    2.22344334009251,
+    2.2117566428841604,
+    2.200245546611276,
+    2.1889027716263603,
+    2.1777214677402923,
+    2.1666951803543077,
+    2.1558178198767366,
+    2.145083634047888,
+    2.134487182846016,
+    2.1240233156895227,
+    2.1136871506866526,
+    2.1034740557148766,
+    2.0933796311387916,
+    2.083399693998304,
+    2.0735302635187427,
+    2.063767547811732,
+    2.054107931650652,
+    2.0445479652175313,
+    2.035084353729619,
+    2.025713947863854,
+    2.016433734906204,
+    2.0072408305605287,
+    1.9981324713584196,
+    1.989106007617438,
+    1.9801588969004766,
+    1.9712886979336592,
+    1.962493064944363,
+    1.9537697423846467,
+    1.9451165600086784,
+    1.9365314282756947,
+    1.9280123340526658,
+    1.9195573365931882,
+    1.9111645637712535,
+    1.9028322085504297,
+    1.8945585256707052,
+    1.8863418285367834,
+    1.8781804862929965,
+    1.8700729210712674,
+    1.8620176053996749,
+    1.8540130597602025,
+    1.846057850285186,
+    1.8381505865828072,
+    1.8302899196827576,
+    1.8224745400938864,
+    1.8147031759662833,
+    1.8069745913508215,
+    1.7992875845497207,
+    1.791640986552163,
+    1.784033659549442,
+    1.7764644955245235,
+    1.768932414911269,
+    1.7614363653189107,
+    1.753975320317672,
+    1.7465482782817228,
+    1.739154261285912,
+    1.7317923140529636,
+    1.7244615029480455,
+    1.7171609150178238,
+    1.7098896570713025,
+    1.7026468547999238,
+    1.6954316519345622,
+    1.6882432094371962,
+    1.6810807047251746,
+    1.6739433309261256,
+    1.6668302961616661,
+    1.6597408228581831,
+    1.6526741470830566,
+    1.645629517904783,
+    1.6386061967755485,
+    1.6316034569348743,
+    1.6246205828330356,
+    1.6176568695730162,
+    1.6107116223698308,
+    1.6037841560260953,
+    1.5968737944227889,
+    1.5899798700241916,
+    1.58310172339603,
+    1.5762387027359073,
+    1.5693901634151246,
+    1.5625554675310458,
+    1.5557339834691772,
+    1.5489250854741743,
+    1.542128153229003,
+    1.5353425714415152,
+    1.5285677294377134,
+    1.5218030207609992,
+    1.5150478427767158,
+    1.5083015962813129,
+    1.501563685115465,
+    1.494833515780495,
+    1.488110497057449,
+    1.4813940396281888,
+    1.4746835556978568,
+    1.4679784586180809,
+    1.4612781625102769,
+    1.4545820818884116,
+    1.4478896312805773,
+    1.4412002248487252,
+    1.4345132760058934,
+    1.427828197030257,
+    1.4211443986753103,
+    1.4144612897754725,
+    1.4077782768464002,
+    1.4010947636792523,
+    1.3944101509281424,
+    1.3877238356899773,
+    1.3810352110758566,
+    1.3743436657731674,
+    1.3676485835974772,
+    1.3609493430332842,
+    1.354245316762636,
+    1.3475358711805883,
+    1.340820365896405,
+    1.334098153219361,
+    1.327368577627927,
+    1.3206309752210572,
+    1.3138846731502214,
+    1.307128989030732,
+    1.3003632303308381,
+    1.2935866937369487,
+    1.2867986644932445,
+    1.279998415713819,
+    1.2731852076653574,
+    1.2663582870182304,
+    1.2595168860637151,
+    1.2526602218948981,
+    1.245787495548628,
+    1.2388978911056883,
+    1.2319905747461368,
+    1.2250646937565315,
+    1.2181193754854824,
+    1.2111537262437,
+    1.2041668301443824,
+    1.1971577478794424,
+    1.1901255154266928,
+    1.1830691426826876,
+    1.1759876120154529,
+    1.1688798767308342,
+    1.1617448594456123,
+    1.1545814503599288,
+    1.1473885054208501,
+    1.1401648443681522,
+    1.132909248652535,
+    1.1256204592155346,
+    1.1182971741193461,
+    1.110938046013577,
+    1.103541679424641,
+    1.0961066278520228,
+    1.0886313906539813,
+    1.0811144097034053,
+    1.0735540657924376,
+    1.0659486747621238,
+    1.0582964833306765,
+    1.0505956645909313,
+    1.0428443131441505,
+    1.0350404398334425,
+    1.0271819660356476,
+    1.019266717465486,
+    1.0112924174399973,
+    1.0032566795446747,
+    0.9951569996350926,
+    0.9869907470990642,
+    0.9787551552942263,
+    0.9704473110642261,
+    0.9620641432230422,
+    0.9536024098810878,
+    0.9450586844681672,
+    0.9364293402865769,
+    0.9277105334020018,
+    0.9188981836495924,
+    0.9099879534967203,
+    0.9009752244612236,
+    0.8918550707329435,
+    0.8826222295851675,
+    0.8732710680888626,
+    0.8637955455533108,
+    0.8541891710081658,
+    0.8444449549091559,
+    0.8345553540863843,
+    0.8245122087522943,
+    0.8143066701352175,
+    0.8039291169899736,
+    0.7933690588406257,
+    0.7826150233072355,
+    0.7716544242245705,
+    0.7604734064301106,
+    0.749056662017818,
+    0.7373872114342983,
+    0.7254461409100025,
+    0.7132122851909788,
+    0.7006618411068181,
+    0.6877678927957916,
+    0.674499822837297,
+    0.660822574244423,
+    0.6466957148949973,
+    0.6320722363860648,
+    0.6168969900077552,
+    0.6011046177559964,
+    0.5846167661063835,
+    0.5673382570538232,
+    0.5491517023271699,
+    0.5299097206615632,
+    0.5094233296020972,
+    0.48744396613924196,
+    0.4636343367908887,
+    0.4375184022078789,
+    0.4083891346119995,
+    0.3751213328783903,
+    0.33573751921443695,
+    0.28617459179208804,
+    0.2152418959849064,
+    0.0,
+];
+
+pub const ZIG_NORM_F: [f64; 257] = [
+    0.0012602859304985956,
+    0.0012602859304985956,
+    0.0026090727461021593,
+    0.0040379725933630236,
+    0.005522403299250986,
+    0.007050875471373216,
+    0.00861658276939872,
+    0.010214971439701459,
+    0.011842757857907879,
+    0.013497450601739867,
+    0.01517708830793531,
+    0.016880083152543142,
+    0.018605121275724622,
+    0.020351096230044483,
+    0.02211706270730882,
+    0.023902203305795823,
+    0.025705804008548817,
+    0.027527235669603013,
+    0.029365939758133255,
+    0.03122141719192019,
+    0.03309321945857846,
+    0.03498094146171602,
+    0.03688421568856722,
+    0.038802707404526064,
+    0.0407361106559409,
+    0.042684144916474424,
+    0.044646552251294463,
+    0.04662309490193038,
+    0.04861355321586854,
+    0.05061772386094778,
+    0.05263541827679219,
+    0.05466646132488892,
+    0.0567106901062029,
+    0.05876795292093374,
+    0.06083810834953988,
+    0.06292102443775814,
+    0.0650165779712429,
+    0.0671246538277885,
+    0.06924514439700676,
+    0.0713779490588904,
+    0.07352297371398132,
+    0.07568013035892711,
+    0.07784933670209605,
+    0.08003051581466307,
+    0.0822235958132029,
+    0.08442850957035347,
+    0.08664519445055807,
+    0.08887359206827589,
+    0.09111364806637376,
+    0.09336531191269101,
+    0.095628536713009,
+    0.09790327903886246,
+    0.10018949876881002,
+    0.10248715894193525,
+    0.10479622562248707,
+    0.1071166677746838,
+    0.1094484571468118,
+    0.11179156816383809,
+    0.11414597782783849,
+    0.11651166562561087,
+    0.11888861344291006,
+    0.1212768054847903,
+    0.12367622820159657,
+    0.1260868702201859,
+    0.12850872227999957,
+    0.13094177717364436,
+    0.13338602969166916,
+    0.13584147657125376,
+    0.13830811644855073,
+    0.1407859498144447,
+    0.14327497897351346,
+    0.14577520800599403,
+    0.14828664273257455,
+    0.15080929068184568,
+    0.15334316106026286,
+    0.1558882647244792,
+    0.15844461415592428,
+    0.161012223437511,
+    0.16359110823236558,
+    0.1661812857644819,
+    0.1687827748012113,
+    0.17139559563750575,
+    0.17401977008183855,
+    0.17665532144373478,
+    0.1793022745228475,
+    0.18196065559952238,
+    0.1846304924267991,
+    0.18731181422380005,
+    0.1900046516704648,
+    0.19270903690358893,
+    0.1954250035141341,
+    0.19815258654577494,
+    0.20089182249465645,
+    0.20364274931033471,
+    0.20640540639788052,
+    0.20917983462112485,
+    0.21196607630703004,
+    0.21476417525117344,
+    0.21757417672433102,
+    0.22039612748015178,
+    0.22323007576391726,
+    0.22607607132237997,
+    0.22893416541467998,
+    0.23180441082433836,
+    0.23468686187232965,
+    0.23758157443123773,
+    0.2404886059405001,
+    0.2434080154227499,
+    0.24633986350126344,
+    0.24928421241852802,
+    0.2522411260559417,
+    0.2552106699546614,
+    0.2581929113376186,
+    0.26118791913272055,
+    0.26419576399726047,
+    0.2672165183435608,
+    0.27025025636587496,
+    0.2732970540685766,
+    0.2763569892956678,
+    0.27943014176163744,
+    0.2825165930837071,
+    0.2856164268155012,
+    0.2887297284821823,
+    0.2918565856170946,
+    0.29499708779996126,
+    0.298151326696685,
+    0.3013193961008025,
+    0.3045013919766494,
+    0.30769741250429145,
+    0.3109075581262859,
+    0.3141319315963365,
+    0.3173706380299129,
+    0.3206237849569047,
+    0.3238914823763904,
+    0.32717384281360057,
+    0.33047098137916275,
+    0.33378301583071757,
+    0.3371100666370053,
+    0.34045225704452103,
+    0.34380971314684994,
+    0.34718256395679287,
+    0.35057094148140533,
+    0.35397498080007594,
+    0.3573948201457797,
+    0.3608306009896472,
+    0.3642824681290031,
+    0.36775056977903164,
+    0.37123505766823856,
+    0.3747360871378902,
+    0.3782538172456183,
+    0.3817884108733928,
+    0.3853400348400765,
+    0.388908860018788,
+    0.39249506145931484,
+    0.3960988185158316,
+    0.3997203149801965,
+    0.4033597392211138,
+    0.40701728432947265,
+    0.41069314827018755,
+    0.4143875340408904,
+    0.4181006498378475,
+    0.4218327092294953,
+    0.4255839313380213,
+    0.4293545410294408,
+    0.43314476911265165,
+    0.4369548525479849,
+    0.44078503466580327,
+    0.4446355653957386,
+    0.4485067015072023,
+    0.4523987068618478,
+    0.45631185267871566,
+    0.4602464178128421,
+    0.4642026890481735,
+    0.4681809614056928,
+    0.4721815384677294,
+    0.47620473271950514,
+    0.480250865909046,
+    0.48432026942668244,
+    0.4884132847054572,
+    0.49253026364386776,
+    0.49667156905248894,
+    0.5008375751261479,
+    0.5050286679434673,
+    0.509245245995747,
+    0.513487720747326,
+    0.5177565172297554,
+    0.5220520746723208,
+    0.5263748471716834,
+    0.530725304403661,
+    0.5351039323804565,
+    0.5395112342569509,
+    0.543947731190025,
+    0.5484139632552646,
+    0.5529104904258311,
+    0.5574378936187647,
+    0.5619967758145232,
+    0.5665877632561631,
+    0.5712115067352519,
+    0.5758686829723524,
+    0.5805599961007896,
+    0.5852861792633699,
+    0.5900479963328245,
+    0.594846243767986,
+    0.5996817526191239,
+    0.6045553906974664,
+    0.6094680649257721,
+    0.6144207238889126,
+    0.619414360605833,
+    0.6244500155470252,
+    0.6295287799248354,
+    0.6346517992876223,
+    0.6398202774530553,
+    0.645035480820821,
+    0.6502987431108154,
+    0.6556114705796959,
+    0.6609751477766618,
+    0.6663913439087488,
+    0.6718617198970807,
+    0.677388036218772,
+    0.6829721616449933,
+    0.6886160830046703,
+    0.6943219161261152,
+    0.7000919181365101,
+    0.7059285013327526,
+    0.7118342488782468,
+    0.7178119326307203,
+    0.7238645334686284,
+    0.7299952645614745,
+    0.7362075981268609,
+    0.7425052963401493,
+    0.748892447219155,
+    0.7553735065070942,
+    0.7619533468367934,
+    0.7686373157984843,
+    0.7754313049811852,
+    0.7823418326548004,
+    0.7893761435660225,
+    0.7965423304229569,
+    0.8038494831709622,
+    0.811307874312654,
+    0.8189291916037001,
+    0.8267268339462192,
+    0.8347162929868812,
+    0.8429156531122018,
+    0.8513462584586755,
+    0.860033621196329,
+    0.8690086880368544,
+    0.8783096558089146,
+    0.8879846607558305,
+    0.8980959218983404,
+    0.9087264400521277,
+    0.9199915050393436,
+    0.9320600759592268,
+    0.9451989534422957,
+    0.9598790918001021,
+    0.977101701267666,
+    1.0,
+];
+
+/// Where the exact unbounded tail begins: `x_tab[1]`, i.e. the edge
+/// of the widest *real* (non tail-box) layer.
+pub const ZIG_EXP_R: f64 = 7.697117470131049;
+
+pub const ZIG_EXP_X: [f64; 257] = [
+    8.69711747013105,
+    7.697117470131049,
+    6.941033629377212,
+    6.478378493832569,
+    6.144164665772472,
+    5.882144315795399,
+    5.666410167454033,
+    5.482890627526062,
+    5.323090505754397,
+    5.181487281301499,
+    5.054288489981303,
+    4.93877708590125,
+    4.832939741025111,
+    4.73524299660174,
+    4.644491885420084,
+    4.5597370617073505,
+    4.480211746528421,
+    4.405287693473571,
+    4.334443680317271,
+    4.267242480277365,
+    4.2033137137351835,
+    4.142340865664051,
+    4.084051310408297,
+    4.028208544647936,
+    3.974606066673788,
+    3.923062500135489,
+    3.8734176703995082,
+    3.825529418522336,
+    3.779270992411667,
+    3.7345288940397965,
+    3.691201090237418,
+    3.649195515760853,
+    3.6084288131289086,
+    3.5688252656483366,
+    3.530315889129343,
+    3.492837654774059,
+    3.4563328211327597,
+    3.4207483572511195,
+    3.3860354424603005,
+    3.352149030900109,
+    3.3190474709707476,
+    3.2866921715990682,
+    3.255047308570449,
+    3.2240795652862633,
+    3.1937579032122394,
+    3.164053358025972,
+    3.1349388580844395,
+    3.1063890623398236,
+    3.0783802152540893,
+    3.0508900166154542,
+    3.0238975044556757,
+    2.9973829495161297,
+    2.9713277599210888,
+    2.945714394895045,
+    2.92052628651274,
+    2.895747768600141,
+    2.8713640120155355,
+    2.847360965635188,
+    2.8237253024500344,
+    2.800444370250737,
+    2.7775061464397557,
+    2.7548991965623437,
+    2.732612636194699,
+    2.710636095867928,
+    2.688959688741803,
+    2.6675739807732657,
+    2.646469963151808,
+    2.625639026797787,
+    2.6050729387408342,
+    2.5847638202141394,
+    2.564704126316904,
+    2.5448866271118686,
+    2.5253043900378263,
+    2.5059507635285923,
+    2.486819361740208,
+    2.4679040502973635,
+    2.4491989329782484,
+    2.4306983392644184,
+    2.4123968126888693,
+    2.394289099921457,
+    2.3763701405361397,
+    2.3586350574093364,
+    2.3410791477030335,
+    2.3236978743901955,
+    2.306486858283579,
+    2.2894418705322686,
+    2.272558825553154,
+    2.2558337743672183,
+    2.239262898312908,
+    2.222842503111036,
+    2.206569013257663,
+    2.190438966723219,
+    2.174449009937774,
+    2.158595893043885,
+    2.142876465399841,
+    2.1272876713173674,
+    2.1118265460190413,
+    2.096490211801714,
+    2.0812758743932243,
+    2.0661808194905746,
+    2.051202409468584,
+    2.0363380802487687,
+    2.0215853383189253,
+    2.0069417578945177,
+    1.992404978213576,
+    1.9779727009573598,
+    1.9636426877895476,
+    1.9494127580071843,
+    1.935280786297051,
+    1.9212447005915274,
+    1.9073024800183869,
+    1.8934521529393076,
+    1.8796917950722107,
+    1.8660195276928273,
+    1.8524335159111749,
+    1.8389319670188793,
+    1.8255131289035191,
+    1.81217528852639,
+    1.7989167704602902,
+    1.7857359354841253,
+    1.772631179231305,
+    1.7596009308890743,
+    1.746643651946074,
+    1.7337578349855711,
+    1.7209420025219349,
+    1.7081947058780576,
+    1.6955145241015377,
+    1.6829000629175537,
+    1.670349953716452,
+    1.6578628525741725,
+    1.6454374393037234,
+    1.6330724165359913,
+    1.620766508828258,
+    1.6085184617988584,
+    1.5963270412864834,
+    1.584191032532689,
+    1.5721092393862297,
+    1.560080483527888,
+    1.5481036037145135,
+    1.536177455041032,
+    1.5243009082192263,
+    1.512472848872117,
+    1.5006921768428167,
+    1.488957805516746,
+    1.4772686611561339,
+    1.4656236822457454,
+    1.4540218188487934,
+    1.4424620319720125,
+    1.4309432929388797,
+    1.4194645827699832,
+    1.4080248915695357,
+    1.3966232179170421,
+    1.385258568263122,
+    1.3739299563284906,
+    1.3626364025050868,
+    1.3513769332583352,
+    1.3401505805295046,
+    1.3289563811371166,
+    1.3177933761763247,
+    1.3066606104151741,
+    1.295557131686601,
+    1.2844819902750126,
+    1.2734342382962411,
+    1.2624129290696153,
+    1.2514171164808525,
+    1.2404458543344066,
+    1.229498195693849,
+    1.2185731922087901,
+    1.2076698934267611,
+    1.196787346088403,
+    1.1859245934042022,
+    1.1750806743109117,
+    1.164254622705679,
+    1.1534454666557747,
+    1.1426522275816728,
+    1.1318739194110785,
+    1.1211095477013302,
+    1.110358108727411,
+    1.0996185885325973,
+    1.0888899619385468,
+    1.0781711915113723,
+    1.0674612264799677,
+    1.0567590016025514,
+    1.0460634359770442,
+    1.0353734317905285,
+    1.0246878730026172,
+    1.0140056239570965,
+    1.0033255279156967,
+    0.9926464055072759,
+    0.9819670530850626,
+    0.9712862409839033,
+    0.9606027116686665,
+    0.949915177764076,
+    0.9392223199552623,
+    0.9285227847472104,
+    0.9178151820700443,
+    0.9070980827156903,
+    0.8963700155898899,
+    0.8856294647617515,
+    0.8748748662910251,
+    0.8641046048110045,
+    0.8533170098423734,
+    0.8425103518103685,
+    0.8316828377342732,
+    0.8208326065544118,
+    0.8099577240574183,
+    0.7990561773554872,
+    0.7881258688694924,
+    0.7771646097591297,
+    0.7661701127354347,
+    0.7551399841819822,
+    0.7440717155005081,
+    0.7329626735843654,
+    0.7218100903087562,
+    0.710611050909655,
+    0.699362481103232,
+    0.6880611327737478,
+    0.6767035680295226,
+    0.6652861413926779,
+    0.653804979847665,
+    0.6422559604245364,
+    0.6306346849334903,
+    0.6189364513948761,
+    0.6071562216203,
+    0.5952885842915029,
+    0.5833277127487695,
+    0.5712673165325883,
+    0.5591005855115406,
+    0.5468201251633106,
+    0.5344178812371656,
+    0.521885051592135,
+    0.5092119824436544,
+    0.49638804551867116,
+    0.48340149165346186,
+    0.470239275082169,
+    0.45688684093142024,
+    0.4433278660735524,
+    0.4295439402254107,
+    0.41551416960035636,
+    0.40121467889627777,
+    0.3866179779411196,
+    0.37169214532991723,
+    0.3563997602583938,
+    0.3406964810648491,
+    0.32452911701690945,
+    0.30783295467493216,
+    0.2905279554912304,
+    0.2725131854784647,
+    0.253658363385912,
+    0.23379048305967473,
+    0.21267151063096662,
+    0.18995868962243184,
+    0.16512762256418728,
+    0.1373049809400126,
+    0.10483850756581865,
+    0.06385216381500144,
+    0.0,
+];
+
+pub const ZIG_EXP_F: [f64; 257] = [
+    0.000454134353841497,
+    0.000454134353841497,
+    0.0009672692823271752,
+    0.001536299780301574,
+    0.002145967743718909,
+    0.0027887987935740783,
+    0.003460264777836907,
+    0.0041572951208338005,
+    0.0048776559835424,
+    0.005619642207205493,
+    0.0063819059373191895,
+    0.007163353183634998,
+    0.00796307743801705,
+    0.008780314985808984,
+    0.00961441364250222,
+    0.010464810181029991,
+    0.01133101359783461,
+    0.0122125924262554,
+    0.013109164931255014,
+    0.014020391403181955,
+    0.014945968011691162,
+    0.01588562183997317,
+    0.016839106826039955,
+    0.017806200410911372,
+    0.01878670074469604,
+    0.019780424338009757,
+    0.020787204072578135,
+    0.0218068875042836,
+    0.02283933540638526,
+    0.023884420511558195,
+    0.024942026419731807,
+    0.02601204664513424,
+    0.027094383780955827,
+    0.028188948763978657,
+    0.02929566022463742,
+    0.030414443910466635,
+    0.031545232172893636,
+    0.03268796350895957,
+    0.03384258215087437,
+    0.035009037697397445,
+    0.03618728478193146,
+    0.037377282772959396,
+    0.038578995503074906,
+    0.039792391023374174,
+    0.041017441380414875,
+    0.042254122413316296,
+    0.04350241356888824,
+    0.04476229773294333,
+    0.04603376107617522,
+    0.0473167929131816,
+    0.048611385573379545,
+    0.04991753428270643,
+    0.05123523705512632,
+    0.052564494593071734,
+    0.05390531019604612,
+    0.05525768967669708,
+    0.05662164128374292,
+    0.057997175631200715,
+    0.05938430563342033,
+    0.060783046445479716,
+    0.06219341540854109,
+    0.06361543199980743,
+    0.06504911778675386,
+    0.06649449638533989,
+    0.0679515934219367,
+    0.06942043649872885,
+    0.07090105516237194,
+    0.07239348087570885,
+    0.07389774699236484,
+    0.0754138887340585,
+    0.07694194317048063,
+    0.07848194920160655,
+    0.08003394754232004,
+    0.08159798070923756,
+    0.08317409300963251,
+    0.08476233053236826,
+    0.08636274114075704,
+    0.08797537446727036,
+    0.089600281910033,
+    0.09123751663104028,
+    0.09288713355604365,
+    0.09454918937605596,
+    0.09622374255043291,
+    0.0979108533114923,
+    0.09961058367063723,
+    0.10132299742595373,
+    0.1030481601712578,
+    0.10478613930657024,
+    0.10653700405000172,
+    0.10830082545103385,
+    0.11007767640518545,
+    0.11186763167005638,
+    0.11367076788274438,
+    0.1154871635786336,
+    0.11731689921155564,
+    0.11916005717532775,
+    0.1210167218266749,
+    0.12288697950954522,
+    0.12477091858083104,
+    0.12666862943751078,
+    0.1285802045452283,
+    0.13050573846833088,
+    0.13244532790138763,
+    0.1343990717022137,
+    0.13636707092642894,
+    0.1383494288635803,
+    0.1403462510748625,
+    0.14235764543247223,
+    0.1443837221606348,
+    0.14642459387834497,
+    0.14848037564386682,
+    0.15055118500103992,
+    0.15263714202744288,
+    0.1547383693844681,
+    0.15685499236936526,
+    0.1589871389693142,
+    0.16113493991759203,
+    0.16329852875190184,
+    0.16547804187493603,
+    0.1676736186172502,
+    0.16988540130252766,
+    0.17211353531532003,
+    0.1743581691713535,
+    0.1766194545904949,
+    0.17889754657247833,
+    0.1811926034754963,
+    0.18350478709776746,
+    0.18583426276219714,
+    0.18818119940425432,
+    0.1905457696631954,
+    0.19292814997677132,
+    0.1953285206795632,
+    0.19774706610509882,
+    0.2001839746919112,
+    0.20263943909370896,
+    0.20511365629383765,
+    0.20760682772422198,
+    0.21011915938898823,
+    0.21265086199297822,
+    0.21520215107537863,
+    0.21777324714870047,
+    0.22036437584335944,
+    0.2229757680581201,
+    0.22560766011668396,
+    0.22826029393071662,
+    0.23093391716962736,
+    0.2336287834374333,
+    0.23634515245705956,
+    0.2390832902624491,
+    0.24184346939887713,
+    0.24462596913189202,
+    0.24743107566532754,
+    0.25025908236886224,
+    0.2531102900156294,
+    0.2559850070304153,
+    0.2588835497490162,
+    0.2618062426893629,
+    0.26475341883506215,
+    0.26772541993204474,
+    0.27072259679905997,
+    0.2737453096528029,
+    0.2767939284485173,
+    0.27986883323697287,
+    0.28297041453878075,
+    0.2860990737370768,
+    0.2892552234896777,
+    0.29243928816189263,
+    0.29565170428126125,
+    0.29889292101558185,
+    0.3021634006756935,
+    0.30546361924459026,
+    0.3087940669345602,
+    0.3121552487741796,
+    0.31554768522712895,
+    0.31897191284495724,
+    0.3224284849560892,
+    0.32591797239355635,
+    0.32944096426413644,
+    0.3329980687618091,
+    0.3365899140286777,
+    0.3402171490667802,
+    0.3438804447045026,
+    0.34758049462163715,
+    0.35131801643748345,
+    0.3550937528667876,
+    0.35890847294875,
+    0.362762973354818,
+    0.3666580797815144,
+    0.3705946484351462,
+    0.3745735676159024,
+    0.37859575940958107,
+    0.38266218149601006,
+    0.38677382908413793,
+    0.3909317369847974,
+    0.39513698183329043,
+    0.39939068447523135,
+    0.40369401253053055,
+    0.4080481831520327,
+    0.41245446599716146,
+    0.4169141864330032,
+    0.4214287289976169,
+    0.4259995411430347,
+    0.43062813728845917,
+    0.4353161032156369,
+    0.4400651008423542,
+    0.44487687341454885,
+    0.44975325116275533,
+    0.45469615747461584,
+    0.459707615642138,
+    0.4647897562504265,
+    0.4699448252839603,
+    0.4751751930373777,
+    0.48048336393045454,
+    0.48587198734188525,
+    0.49134386959403287,
+    0.4969019872415499,
+    0.5025495018413481,
+    0.5082897764106432,
+    0.5141263938147489,
+    0.5200631773682339,
+    0.5261042139836201,
+    0.5322538802630437,
+    0.5385168720028622,
+    0.5448982376724401,
+    0.5514034165406417,
+    0.5580382822625879,
+    0.5648091929124006,
+    0.5717230486648262,
+    0.5787873586028454,
+    0.5860103184772684,
+    0.5934009016917338,
+    0.6009689663652326,
+    0.6087253820796223,
+    0.6166821809152079,
+    0.6248527387036662,
+    0.6332519942143664,
+    0.6418967164272664,
+    0.6508058334145714,
+    0.6600008410790001,
+    0.6695063167319252,
+    0.6793505722647658,
+    0.6895664961170784,
+    0.7001926550827886,
+    0.7112747608050765,
+    0.7228676595935725,
+    0.735038092431424,
+    0.7478686219851957,
+    0.7614633888498968,
+    0.7759568520401162,
+    0.7915276369724963,
+    0.808421651523009,
+    0.8269932966430511,
+    0.8477855006239905,
+    0.8717043323812047,
+    0.9004699299257478,
+    0.9381436808621766,
+    1.0,
+];
+
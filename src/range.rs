@@ -0,0 +1,234 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A distribution generating numbers uniformly over a given range,
+//! without the bias that `rng.gen::<T>() % n` introduces.
+
+use core::mem;
+
+use Rng;
+
+/// Generate an unbiased integer uniformly distributed over `[0, n)`,
+/// using Lemire's multiply-shift method.
+///
+/// A full-width word `x` is drawn and multiplied into a double-width
+/// product `m = x * n`; the low half of `m` tells us how close `x`
+/// landed to a multiple of `2^32 / n`, and the high half is the
+/// answer. Unlike `x % n`, this is unbiased: the rejection threshold
+/// `t = (-n) % n` is the exact count of low-half values that would
+/// otherwise make some residue class more likely than others, so `x`
+/// is redrawn only on that case, which for most `n` happens close to
+/// never and never costs more than the one division used to compute
+/// `t`.
+#[inline]
+pub fn gen_below<R: Rng>(rng: &mut R, n: u32) -> u32 {
+    debug_assert!(n > 0, "gen_below called with n == 0");
+    let mut m = (rng.next_u32() as u64) * (n as u64);
+    if (m as u32) < n {
+        let t = n.wrapping_neg() % n;
+        while (m as u32) < t {
+            m = (rng.next_u32() as u64) * (n as u64);
+        }
+    }
+    (m >> 32) as u32
+}
+
+/// 64-bit widening multiply `a * b`, returned as `(hi, lo)`.
+///
+/// Split into 32-bit halves so this works without a 128-bit integer
+/// type, matching the `i128_support`-gated handling of `u128`
+/// elsewhere in this crate.
+#[inline]
+fn wmul64(a: u64, b: u64) -> (u64, u64) {
+    let a_lo = a & 0xffff_ffff;
+    let a_hi = a >> 32;
+    let b_lo = b & 0xffff_ffff;
+    let b_hi = b >> 32;
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 32) + (hi_lo & 0xffff_ffff) + lo_hi;
+    let hi = hi_hi + (hi_lo >> 32) + (cross >> 32);
+    let lo = (cross << 32) | (lo_lo & 0xffff_ffff);
+    (hi, lo)
+}
+
+/// The 64-bit counterpart of `gen_below`.
+#[inline]
+pub fn gen_below64<R: Rng>(rng: &mut R, n: u64) -> u64 {
+    debug_assert!(n > 0, "gen_below64 called with n == 0");
+    let (mut hi, mut lo) = wmul64(rng.next_u64(), n);
+    if lo < n {
+        let t = n.wrapping_neg() % n;
+        while lo < t {
+            let (h, l) = wmul64(rng.next_u64(), n);
+            hi = h;
+            lo = l;
+        }
+    }
+    hi
+}
+
+/// A distribution generating values uniformly over `[low, high)`.
+///
+/// Stores `low` and the width `high - low`. Each `sample` call goes
+/// through `gen_below`/`gen_below64`, which computes the rejection
+/// threshold within that call when it is needed; the threshold is not
+/// cached in the `Range`.
+pub struct Range<X> {
+    low: X,
+    range: X,
+}
+
+macro_rules! range_impl {
+    ($ty:ty, $unsigned:ty, $gen_below:ident) => {
+        impl Range<$ty> {
+            /// Create a new `Range` sampling values in `[low, high)`.
+            ///
+            /// Panics if `low >= high`.
+            #[inline]
+            pub fn new(low: $ty, high: $ty) -> Range<$ty> {
+                assert!(low < high, "Range::new called with low >= high");
+                Range { low, range: high.wrapping_sub(low) as $ty }
+            }
+
+            /// Sample a value from this range.
+            #[inline]
+            pub fn sample<R: Rng>(&self, rng: &mut R) -> $ty {
+                let off = $gen_below(rng, self.range as $unsigned);
+                self.low.wrapping_add(off as $ty)
+            }
+        }
+    }
+}
+
+range_impl!{ u32, u32, gen_below }
+range_impl!{ i32, u32, gen_below }
+range_impl!{ u64, u64, gen_below64 }
+range_impl!{ i64, u64, gen_below64 }
+
+impl Range<usize> {
+    /// Create a new `Range` sampling values in `[low, high)`.
+    ///
+    /// Panics if `low >= high`.
+    #[inline]
+    pub fn new(low: usize, high: usize) -> Range<usize> {
+        assert!(low < high, "Range::new called with low >= high");
+        Range { low, range: high.wrapping_sub(low) }
+    }
+
+    /// Sample a value from this range.
+    #[inline]
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        if mem::size_of::<usize>() == 4 {
+            self.low.wrapping_add(gen_below(rng, self.range as u32) as usize)
+        } else {
+            self.low.wrapping_add(gen_below64(rng, self.range as u64) as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gen_below, gen_below64, Range};
+
+    #[test]
+    fn below_small_n_covers_every_residue() {
+        let mut rng = ::test::rng(601);
+        for n in 1u32..20 {
+            let mut seen = vec![false; n as usize];
+            for _ in 0..10_000 {
+                let x = gen_below(&mut rng, n);
+                assert!(x < n);
+                seen[x as usize] = true;
+            }
+            assert!(seen.iter().all(|&hit| hit),
+                    "n={} did not hit every residue class", n);
+        }
+    }
+
+    #[test]
+    fn below64_small_n_covers_every_residue() {
+        let mut rng = ::test::rng(602);
+        for n in 1u64..20 {
+            let mut seen = vec![false; n as usize];
+            for _ in 0..10_000 {
+                let x = gen_below64(&mut rng, n);
+                assert!(x < n);
+                seen[x as usize] = true;
+            }
+            assert!(seen.iter().all(|&hit| hit),
+                    "n={} did not hit every residue class", n);
+        }
+    }
+
+    // "every residue hit at least once" also passes for a biased
+    // generator (e.g. a plain `% n`), since for small `n` and 10,000
+    // draws even a skewed distribution eventually hits everything. Use
+    // a chi-squared goodness-of-fit statistic against the uniform
+    // distribution instead, which a biased generator would fail.
+    fn chi_squared(counts: &[u32], expected: f64) -> f64 {
+        counts.iter()
+            .map(|&c| {
+                let diff = c as f64 - expected;
+                diff * diff / expected
+            })
+            .sum()
+    }
+
+    #[test]
+    fn below_is_uniform() {
+        let mut rng = ::test::rng(604);
+        let draws = 100_000;
+        for n in [2u32, 5, 7, 16].iter().cloned() {
+            let mut counts = vec![0u32; n as usize];
+            for _ in 0..draws {
+                counts[gen_below(&mut rng, n) as usize] += 1;
+            }
+            let expected = draws as f64 / n as f64;
+            // with n - 1 degrees of freedom, 3 * (n - 1) is roughly the
+            // 92nd, 98th, 99.4th and 99.99th percentile for n = 2, 5, 7
+            // and 16, so for n < 16 this bound is stricter than a 99.9%
+            // critical value.
+            let chi2 = chi_squared(&counts, expected);
+            assert!(chi2 < 3.0 * (n - 1) as f64,
+                    "n={} chi-squared was {} (counts={:?})", n, chi2, counts);
+        }
+    }
+
+    #[test]
+    fn below64_is_uniform() {
+        let mut rng = ::test::rng(605);
+        let draws = 100_000;
+        for n in [2u64, 5, 7, 16].iter().cloned() {
+            let mut counts = vec![0u32; n as usize];
+            for _ in 0..draws {
+                counts[gen_below64(&mut rng, n) as usize] += 1;
+            }
+            let expected = draws as f64 / n as f64;
+            let chi2 = chi_squared(&counts, expected);
+            assert!(chi2 < 3.0 * (n - 1) as f64,
+                    "n={} chi-squared was {} (counts={:?})", n, chi2, counts);
+        }
+    }
+
+    #[test]
+    fn range_stays_in_bounds() {
+        let mut rng = ::test::rng(603);
+        let r = Range::<i32>::new(-5, 5);
+        for _ in 0..10_000 {
+            let x = r.sample(&mut rng);
+            assert!(x >= -5 && x < 5);
+        }
+    }
+}
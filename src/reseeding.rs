@@ -0,0 +1,148 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An `Rng` adapter that reseeds its inner generator after a
+//! configurable number of generated bytes, protecting long-running
+//! generators against state compromise without changing call sites.
+
+use SeedableRng;
+use Rng;
+
+/// Something that can refresh an `R`'s state in place.
+///
+/// Implementations typically pull fresh seed material from an
+/// external source, such as the OS RNG or another `Rng`.
+pub trait Reseeder<R: ?Sized> {
+    /// Reseed `rng`.
+    fn reseed(&mut self, rng: &mut R);
+}
+
+/// A `Reseeder` that reseeds its target by drawing a fresh instance
+/// from another `Rng` via `SeedableRng::from_rng`. This composes with
+/// the blanket `impl<T: SeedableRng> Rand for T` in `rand_impls`,
+/// since that impl is exactly `from_rng` under the hood.
+pub struct ReseedWithRng<Rsrc>(pub Rsrc);
+
+impl<R: SeedableRng, Rsrc: Rng> Reseeder<R> for ReseedWithRng<Rsrc> {
+    fn reseed(&mut self, rng: &mut R) {
+        *rng = R::from_rng(&mut self.0).unwrap();
+    }
+}
+
+/// An `Rng` that wraps another `Rng`, transparently reseeding it from
+/// `Rsdr` once `generation_threshold` bytes have been generated
+/// across any combination of `next_u32`/`next_u64`/`fill_bytes`
+/// calls.
+pub struct ReseedingRng<R, Rsdr> {
+    rng: R,
+    generation_threshold: u64,
+    bytes_generated: u64,
+    reseeder: Rsdr,
+}
+
+impl<R: Rng, Rsdr: Reseeder<R>> ReseedingRng<R, Rsdr> {
+    /// Create a new `ReseedingRng` wrapping `rng`, reseeding via
+    /// `reseeder` every time `generation_threshold` bytes have been
+    /// produced.
+    pub fn new(rng: R, generation_threshold: u64, reseeder: Rsdr) -> ReseedingRng<R, Rsdr> {
+        ReseedingRng {
+            rng,
+            generation_threshold,
+            bytes_generated: 0,
+            reseeder,
+        }
+    }
+
+    /// Reseed the inner generator now, regardless of the byte count.
+    pub fn reseed(&mut self) {
+        self.reseeder.reseed(&mut self.rng);
+        self.bytes_generated = 0;
+    }
+
+    #[inline]
+    fn account(&mut self, bytes: u64) {
+        self.bytes_generated += bytes;
+        if self.bytes_generated >= self.generation_threshold {
+            self.reseed();
+        }
+    }
+}
+
+impl<R: Rng, Rsdr: Reseeder<R>> Rng for ReseedingRng<R, Rsdr> {
+    fn next_u32(&mut self) -> u32 {
+        let value = self.rng.next_u32();
+        self.account(4);
+        value
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        self.account(8);
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+        self.account(dest.len() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Reseeder, ReseedingRng};
+    use Rng;
+
+    struct CountingRng(u32);
+    impl Rng for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 += 1;
+            self.0
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.next_u32() as u64
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = self.next_u32() as u8;
+            }
+        }
+    }
+
+    struct CountReseeds(u32);
+    impl Reseeder<CountingRng> for CountReseeds {
+        fn reseed(&mut self, rng: &mut CountingRng) {
+            self.0 += 1;
+            rng.0 = 0;
+        }
+    }
+
+    #[test]
+    fn reseeds_after_threshold_bytes() {
+        let mut rng = ReseedingRng::new(CountingRng(0), 16, CountReseeds(0));
+        for _ in 0..3 {
+            rng.next_u32();
+        }
+        assert_eq!(rng.reseeder.0, 0);
+        for _ in 0..2 {
+            rng.next_u32();
+        }
+        // 5 * 4 = 20 bytes >= threshold of 16
+        assert_eq!(rng.reseeder.0, 1);
+    }
+
+    #[test]
+    fn manual_reseed_resets_counter() {
+        let mut rng = ReseedingRng::new(CountingRng(0), 1_000_000, CountReseeds(0));
+        rng.next_u32();
+        rng.reseed();
+        assert_eq!(rng.reseeder.0, 1);
+        assert_eq!(rng.bytes_generated, 0);
+    }
+}
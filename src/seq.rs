@@ -0,0 +1,173 @@
+// Copyright 2013-2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// https://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shuffling and sampling helpers over slices and iterators.
+//!
+//! None of this is expressible through the per-type `Rand` impls;
+//! each function here draws indices with the unbiased bounded
+//! generator in `range` rather than `rng.gen::<usize>() % n`.
+
+use core::mem;
+
+use range::gen_below64;
+use Rng;
+
+#[inline]
+fn below<R: Rng>(rng: &mut R, n: usize) -> usize {
+    if mem::size_of::<usize>() == 4 {
+        ::range::gen_below(rng, n as u32) as usize
+    } else {
+        gen_below64(rng, n as u64) as usize
+    }
+}
+
+/// Shuffle a mutable slice in place using the Fisher-Yates algorithm.
+///
+/// Walks `i` from `len - 1` down to `1`, swapping the element at `i`
+/// with one drawn uniformly from `[0, i]`, so every permutation of
+/// `values` is equally likely.
+pub fn shuffle<T, R: Rng>(values: &mut [T], rng: &mut R) {
+    let mut i = values.len();
+    while i >= 2 {
+        i -= 1;
+        let j = below(rng, i + 1);
+        values.swap(i, j);
+    }
+}
+
+/// Choose `amount` indices out of `[0, length)` without replacement,
+/// using Floyd's algorithm.
+///
+/// Unlike a full `shuffle`, this draws only `amount` random indices and
+/// its cost does not grow with `length`, which matters when `amount` is
+/// much smaller than `length`. Membership is checked by a linear scan of
+/// the result, so it runs in `O(amount^2)` time, not `O(amount)`.
+/// Panics if `amount > length`.
+pub fn sample_indices<R: Rng>(rng: &mut R, length: usize, amount: usize) -> Vec<usize> {
+    assert!(amount <= length, "sample_indices: amount > length");
+
+    let mut result = Vec::with_capacity(amount);
+    // a HashSet would need `std`; this crate is built to also work
+    // `no_std`, so track membership in the (small, O(amount)) result
+    // vector itself.
+    for j in (length - amount)..length {
+        let t = below(rng, j + 1);
+        if result.contains(&t) {
+            result.push(j);
+        } else {
+            result.push(t);
+        }
+    }
+    result
+}
+
+/// Choose `amount` references into `values` without replacement.
+///
+/// Panics if `amount > values.len()`.
+pub fn sample<'a, T, R: Rng>(rng: &mut R, values: &'a [T], amount: usize) -> Vec<&'a T> {
+    sample_indices(rng, values.len(), amount)
+        .into_iter()
+        .map(|i| &values[i])
+        .collect()
+}
+
+/// Reservoir-sample `amount` items out of an iterator of unknown
+/// length, returning every item with equal probability `amount / n`
+/// where `n` is the total number of items seen.
+///
+/// Fills a buffer with the first `amount` items, then for the `i`-th
+/// item thereafter draws `r` uniform in `[0, i]` and replaces slot
+/// `r` if `r < amount`.
+pub fn sample_reservoir<T, I, R: Rng>(rng: &mut R, mut iter: I, amount: usize) -> Vec<T>
+    where I: Iterator<Item = T>
+{
+    let mut reservoir: Vec<T> = Vec::with_capacity(amount);
+    for item in iter.by_ref().take(amount) {
+        reservoir.push(item);
+    }
+
+    for (i, item) in (amount..).zip(iter) {
+        let r = below(rng, i + 1);
+        if r < amount {
+            reservoir[r] = item;
+        }
+    }
+
+    reservoir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shuffle, sample_indices, sample, sample_reservoir};
+
+    #[test]
+    fn shuffle_is_a_permutation() {
+        let mut rng = ::test::rng(401);
+        let mut v: Vec<i32> = (0..20).collect();
+        shuffle(&mut v, &mut rng);
+        let mut sorted = v.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sample_indices_are_distinct_and_in_range() {
+        let mut rng = ::test::rng(402);
+        for _ in 0..1000 {
+            let idx = sample_indices(&mut rng, 10, 4);
+            assert_eq!(idx.len(), 4);
+            for &i in &idx {
+                assert!(i < 10);
+            }
+            let mut sorted = idx.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), idx.len());
+        }
+    }
+
+    #[test]
+    fn sample_picks_distinct_elements() {
+        let mut rng = ::test::rng(403);
+        let values: Vec<i32> = (0..50).collect();
+        let chosen = sample(&mut rng, &values, 5);
+        assert_eq!(chosen.len(), 5);
+        let mut sorted = chosen.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 5);
+    }
+
+    #[test]
+    fn reservoir_sample_has_right_size_and_values() {
+        let mut rng = ::test::rng(404);
+        let reservoir = sample_reservoir(&mut rng, 0..1000, 10);
+        assert_eq!(reservoir.len(), 10);
+        for &v in &reservoir {
+            assert!(v < 1000);
+        }
+    }
+
+    #[test]
+    fn reservoir_sample_reaches_items_past_initial_fill() {
+        // items after the first `amount` should be able to displace the
+        // initial fill, so some run should return a value >= `amount`.
+        let mut rng = ::test::rng(405);
+        let mut seen_late = false;
+        for _ in 0..2000 {
+            let reservoir = sample_reservoir(&mut rng, 0..20, 5);
+            if reservoir.iter().any(|&v| v >= 5) {
+                seen_late = true;
+                break;
+            }
+        }
+        assert!(seen_late, "reservoir sampling never picked an item past the initial fill");
+    }
+}